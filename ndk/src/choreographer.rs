@@ -0,0 +1,132 @@
+//! Bindings for [`AChoreographer`]
+//!
+//! [`AChoreographer`]: https://developer.android.com/ndk/reference/group/choreographer
+#![cfg(feature = "api-level-24")]
+
+use std::ptr::NonNull;
+#[cfg(feature = "api-level-29")]
+use std::{os::raw::c_void, time::Duration};
+
+#[cfg(feature = "api-level-29")]
+use crate::utils::abort_on_panic;
+
+/// Callback invoked by [`Choreographer::post_frame_callback()`], with the time at which the frame
+/// started rendering, in the [`CLOCK_MONOTONIC`] time base.
+///
+/// [`CLOCK_MONOTONIC`]: https://man7.org/linux/man-pages/man3/clock_gettime.3.html
+#[cfg(feature = "api-level-29")]
+pub type FrameCallback = Box<dyn FnOnce(Duration)>;
+
+/// Identifier of a frame timeline, as returned by
+/// [`AChoreographerFrameCallbackData_getFrameTimelineVsyncId()`].
+///
+/// It is used to tell the system which frame timeline a buffer was rendered for. Ids that are
+/// stale or invalid are not an error; the system ignores them.
+///
+/// [`AChoreographerFrameCallbackData_getFrameTimelineVsyncId()`]: https://developer.android.com/ndk/reference/group/choreographer#achoreographerframecallbackdata_getframetimelinevsyncid
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[doc(alias = "AVsyncId")]
+pub struct VsyncId(pub ffi::AVsyncId);
+
+/// A native [`AChoreographer *`]
+///
+/// [`Choreographer`] coordinates the timing of frame rendering. It is inherently thread-local:
+/// every thread with an [`ALooper`][crate::looper::ThreadLooper] has its own instance, and
+/// callbacks posted to it are invoked from that looper on the same thread.
+///
+/// [`AChoreographer *`]: https://developer.android.com/ndk/reference/group/choreographer#achoreographer
+#[derive(Debug)]
+#[doc(alias = "AChoreographer")]
+pub struct Choreographer {
+    ptr: NonNull<ffi::AChoreographer>,
+}
+
+impl Choreographer {
+    /// Get the [`Choreographer`] instance for the current thread.
+    ///
+    /// Returns [`None`] if the current thread does not have an
+    /// [`ALooper`][crate::looper::ThreadLooper], which must be prepared beforehand using
+    /// [`ThreadLooper::prepare()`][crate::looper::ThreadLooper::prepare()].
+    #[doc(alias = "AChoreographer_getInstance")]
+    pub fn instance() -> Option<Self> {
+        let ptr = unsafe { ffi::AChoreographer_getInstance() };
+        Some(Self {
+            ptr: NonNull::new(ptr)?,
+        })
+    }
+
+    /// Returns a pointer to the native [`ffi::AChoreographer`].
+    pub fn ptr(&self) -> NonNull<ffi::AChoreographer> {
+        self.ptr
+    }
+
+    /// Post a callback to be run on the next frame.
+    ///
+    /// The callback is invoked exactly once, on the thread that this [`Choreographer`] belongs to,
+    /// with the frame time as a [`Duration`] since the [`CLOCK_MONOTONIC`] epoch. To drive a
+    /// render loop, post a new callback from within the callback for every frame.
+    ///
+    /// The callback is dropped after it runs. It is leaked if the looper of this thread is torn
+    /// down before the next frame.
+    ///
+    /// The deprecated `AChoreographer_postFrameCallback` from API level 24 is not wrapped, as it
+    /// reports the frame time as a `long` which overflows every few seconds on 32-bit targets.
+    ///
+    /// ```no_run
+    /// use ndk::choreographer::Choreographer;
+    ///
+    /// fn post_next_frame() {
+    ///     let choreographer = Choreographer::instance().expect("Thread has no looper");
+    ///     choreographer.post_frame_callback(Box::new(|frame_time| {
+    ///         println!("Rendering frame at {frame_time:?}");
+    ///         post_next_frame();
+    ///     }));
+    /// }
+    /// ```
+    ///
+    /// [`CLOCK_MONOTONIC`]: https://man7.org/linux/man-pages/man3/clock_gettime.3.html
+    #[cfg(feature = "api-level-29")]
+    #[doc(alias = "AChoreographer_postFrameCallback64")]
+    pub fn post_frame_callback(&self, callback: FrameCallback) {
+        let data = Box::into_raw(Box::new(callback)) as *mut c_void;
+        unsafe {
+            ffi::AChoreographer_postFrameCallback64(self.ptr.as_ptr(), Some(frame_callback), data)
+        }
+    }
+
+    /// Post a callback to be run on the first frame after `delay` has elapsed.
+    ///
+    /// See [`post_frame_callback()`][Self::post_frame_callback()] for how the callback is invoked.
+    /// `delay` is rounded down to whole milliseconds.
+    ///
+    /// It panics if `delay` is larger than expressible as a [`u32`] of milliseconds (roughly 49
+    /// days).
+    #[cfg(feature = "api-level-29")]
+    #[doc(alias = "AChoreographer_postFrameCallbackDelayed64")]
+    pub fn post_frame_callback_delayed(&self, callback: FrameCallback, delay: Duration) {
+        let delay = delay.as_millis().try_into().expect("u32 overflow in delay");
+        let data = Box::into_raw(Box::new(callback)) as *mut c_void;
+        unsafe {
+            ffi::AChoreographer_postFrameCallbackDelayed64(
+                self.ptr.as_ptr(),
+                Some(frame_callback),
+                data,
+                delay,
+            )
+        }
+    }
+}
+
+/// Trampoline for [`ffi::AChoreographer_frameCallback64`], which takes back ownership of the
+/// [`FrameCallback`] boxed in [`Choreographer::post_frame_callback()`].
+#[cfg(feature = "api-level-29")]
+unsafe extern "C" fn frame_callback(frame_time_nanos: i64, data: *mut c_void) {
+    abort_on_panic(|| {
+        let callback = Box::from_raw(data as *mut FrameCallback);
+        callback(Duration::from_nanos(
+            frame_time_nanos
+                .try_into()
+                .expect("Frame time should not be negative"),
+        ))
+    })
+}
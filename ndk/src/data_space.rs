@@ -3,15 +3,31 @@
 //! [`ADataSpace`]: https://developer.android.com/ndk/reference/group/a-data-space#group___a_data_space_1ga2759ad19cae46646cc5f7002758c4a1c
 #![cfg(feature = "api-level-28")]
 
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use num_enum::{IntoPrimitive, TryFromPrimitive, TryFromPrimitiveError};
+use thiserror::Error;
+
+use crate::hardware_buffer_format::HardwareBufferFormat;
 
 /// Describes how to interpret colors.
 ///
 /// <https://developer.android.com/ndk/reference/group/a-data-space#group___a_data_space_1ga2759ad19cae46646cc5f7002758c4a1c>
+///
+/// With the `serde` feature enabled, [`DataSpace`] is serialized by its variant name:
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use ndk::data_space::DataSpace;
+///
+/// let json = serde_json::to_string(&DataSpace::DisplayP3).unwrap();
+/// assert_eq!(json, r#""DisplayP3""#);
+/// assert_eq!(serde_json::from_str::<DataSpace>(&json).unwrap(), DataSpace::DisplayP3);
+/// # }
+/// ```
 #[repr(u32)]
 #[derive(Clone, Copy, Hash, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc(alias = "ADataSpace")]
 #[non_exhaustive]
 pub enum DataSpace {
@@ -258,6 +274,58 @@ impl fmt::Display for DataSpace {
     }
 }
 
+/// Error returned by [`DataSpace::from_str()`] when a string doesn't name a known [`DataSpace`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Error)]
+#[error("Unknown DataSpace name")]
+pub struct ParseDataSpaceError;
+
+impl FromStr for DataSpace {
+    type Err = ParseDataSpaceError;
+
+    /// Parses a [`DataSpace`] from its name, case-insensitively.
+    ///
+    /// Accepts the names produced by the [`Display`][fmt::Display] implementation (e.g.
+    /// `"Bt2020Pq"`) as well as the NDK constant names with or without their `ADATASPACE_` prefix
+    /// (e.g. `"BT2020_PQ"` or `"ADATASPACE_BT2020_PQ"`), so that names round-trip through
+    /// [`ToString::to_string()`].
+    ///
+    /// ```
+    /// # use ndk::data_space::{DataSpace, ParseDataSpaceError};
+    /// assert_eq!("Bt2020Pq".parse(), Ok(DataSpace::Bt2020Pq));
+    /// assert_eq!("BT2020_PQ".parse(), Ok(DataSpace::Bt2020Pq));
+    /// assert_eq!("adataspace_scrgb_linear".parse(), Ok(DataSpace::ScrgbLinear));
+    /// assert_eq!(DataSpace::DisplayP3.to_string().parse(), Ok(DataSpace::DisplayP3));
+    /// assert_eq!("sRGB-ish".parse::<DataSpace>(), Err(ParseDataSpaceError));
+    /// assert_eq!("s_r_g_b".parse::<DataSpace>(), Err(ParseDataSpaceError));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_ascii_lowercase();
+        let name = name.strip_prefix("adataspace_").unwrap_or(&name);
+        Ok(match name {
+            "unknown" => Self::Unknown,
+            "adobergb" | "adobe_rgb" => Self::AdobeRgb,
+            "bt2020" => Self::Bt2020,
+            "bt2020hlg" | "bt2020_hlg" => Self::Bt2020Hlg,
+            "bt2020ituhlg" | "bt2020_itu_hlg" => Self::Bt2020ItuHlg,
+            "bt2020itupq" | "bt2020_itu_pq" => Self::Bt2020ItuPq,
+            "bt2020pq" | "bt2020_pq" => Self::Bt2020Pq,
+            "bt601_525" => Self::Bt601_525,
+            "bt601_625" => Self::Bt601_625,
+            "bt709" => Self::Bt709,
+            "dcip3" | "dci_p3" => Self::DciP3,
+            "displayp3" | "display_p3" => Self::DisplayP3,
+            "jfif" => Self::Jfif,
+            "scrgb" => Self::Scrgb,
+            "scrgblinear" | "scrgb_linear" => Self::ScrgbLinear,
+            "srgb" => Self::Srgb,
+            "srgblinear" | "srgb_linear" => Self::SrgbLinear,
+            "depth" => Self::Depth,
+            "dynamicdepth" | "dynamic_depth" => Self::DynamicDepth,
+            _ => return Err(ParseDataSpaceError),
+        })
+    }
+}
+
 impl fmt::Debug for DataSpace {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -303,6 +371,54 @@ impl DataSpace {
         let range = self as u32 & ffi::ADataSpace::RANGE_MASK.0;
         DataSpaceRange::try_from(range)
     }
+
+    /// Returns the [`DataSpace`] that content in the given [`HardwareBufferFormat`] is most
+    /// commonly encoded in.
+    ///
+    /// These are only recommendations to use consistent defaults when pairing a freshly allocated
+    /// buffer with a data space, and can be overridden whenever the content is known to be encoded
+    /// differently:
+    ///
+    /// - 8-bit (and smaller) `RGB(A)` formats map to [`DataSpace::Srgb`];
+    /// - [`HardwareBufferFormat::R16G16B16A16_FLOAT`] maps to [`DataSpace::ScrgbLinear`];
+    /// - [`HardwareBufferFormat::R10G10B10A2_UNORM`] maps to [`DataSpace::Bt2020Pq`];
+    /// - [`HardwareBufferFormat::YCbCr_P010`] maps to [`DataSpace::Bt2020ItuPq`], as `10`-bit
+    ///   video content is typically encoded in limited range;
+    /// - All other formats, including depth, stencil, [`HardwareBufferFormat::BLOB`] and `8`-bit
+    ///   `YCbCr` (whose standard depends on the image size), map to [`DataSpace::Unknown`].
+    ///
+    /// ```
+    /// # use ndk::data_space::DataSpace;
+    /// # use ndk::hardware_buffer_format::HardwareBufferFormat;
+    /// assert_eq!(
+    ///     DataSpace::default_for_format(HardwareBufferFormat::R8G8B8A8_UNORM),
+    ///     DataSpace::Srgb
+    /// );
+    /// assert_eq!(
+    ///     DataSpace::default_for_format(HardwareBufferFormat::R16G16B16A16_FLOAT),
+    ///     DataSpace::ScrgbLinear
+    /// );
+    /// assert_eq!(
+    ///     DataSpace::default_for_format(HardwareBufferFormat::R10G10B10A2_UNORM),
+    ///     DataSpace::Bt2020Pq
+    /// );
+    /// assert_eq!(
+    ///     DataSpace::default_for_format(HardwareBufferFormat::BLOB),
+    ///     DataSpace::Unknown
+    /// );
+    /// ```
+    pub fn default_for_format(format: HardwareBufferFormat) -> Self {
+        match format {
+            HardwareBufferFormat::R8G8B8A8_UNORM
+            | HardwareBufferFormat::R8G8B8X8_UNORM
+            | HardwareBufferFormat::R8G8B8_UNORM
+            | HardwareBufferFormat::R5G6B5_UNORM => Self::Srgb,
+            HardwareBufferFormat::R16G16B16A16_FLOAT => Self::ScrgbLinear,
+            HardwareBufferFormat::R10G10B10A2_UNORM => Self::Bt2020Pq,
+            HardwareBufferFormat::YCbCr_P010 => Self::Bt2020ItuPq,
+            _ => Self::Unknown,
+        }
+    }
 }
 
 /// Color-description aspects.
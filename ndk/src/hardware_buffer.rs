@@ -186,14 +186,28 @@ impl HardwareBuffer {
     /// using [`HardwareBuffer::acquire()`].
     ///
     /// [Java `HardwareBuffer`]: https://developer.android.com/reference/android/hardware/HardwareBuffer
+    #[doc(alias = "AHardwareBuffer_fromHardwareBuffer")]
     pub unsafe fn from_jni(env: *mut JNIEnv, hardware_buffer: jobject) -> Self {
         let ptr = ffi::AHardwareBuffer_fromHardwareBuffer(env, hardware_buffer);
 
         Self::from_ptr(NonNull::new_unchecked(ptr))
     }
 
+    /// Return a new [Java `HardwareBuffer`] object that wraps this [`HardwareBuffer`]
+    ///
+    /// The Java object acquires its own reference to the underlying [`ffi::AHardwareBuffer`],
+    /// which is released when that object is closed or garbage collected, independently of the
+    /// lifetime of `self`. The returned [`jobject`] is a JNI local reference; call `NewGlobalRef`
+    /// on it to keep it beyond the current native frame.
+    ///
+    /// Use [`HardwareBuffer::from_jni()`] to convert in the other direction, for example for
+    /// buffers handed out by `ImageReader` or `MediaCodec` on the Java side.
+    ///
     /// # Safety
     /// By calling this function, you assert that `env` is a valid pointer to a [`JNIEnv`].
+    ///
+    /// [Java `HardwareBuffer`]: https://developer.android.com/reference/android/hardware/HardwareBuffer
+    #[doc(alias = "AHardwareBuffer_toHardwareBuffer")]
     pub unsafe fn to_jni(&self, env: *mut JNIEnv) -> jobject {
         ffi::AHardwareBuffer_toHardwareBuffer(env, self.as_ptr())
     }
@@ -232,7 +246,69 @@ impl HardwareBuffer {
         res == 1
     }
 
+    /// Pick the first format in `candidates` that can be allocated with the remaining fields of
+    /// `desc`.
+    ///
+    /// This is useful to find a format that both the producer and the consumer of a buffer
+    /// support, before allocating it. `candidates` should be ordered by preference, most
+    /// preferred first. The [`HardwareBufferDesc::format`] field of `desc` is ignored and replaced
+    /// by each candidate in turn before testing it with [`HardwareBuffer::is_supported()`], while
+    /// all other fields (including the [`HardwareBufferUsage`] flags required by both sides) are
+    /// passed through unmodified.
+    ///
+    /// Returns [`None`] if none of the candidates are supported.
+    ///
+    /// ```no_run
+    /// # use ndk::hardware_buffer::{HardwareBuffer, HardwareBufferDesc, HardwareBufferUsage};
+    /// # use ndk::hardware_buffer_format::HardwareBufferFormat;
+    /// let desc = HardwareBufferDesc {
+    ///     width: 1920,
+    ///     height: 1080,
+    ///     layers: 1,
+    ///     format: HardwareBufferFormat::R8G8B8A8_UNORM,
+    ///     usage: HardwareBufferUsage::COMPOSER_OVERLAY,
+    ///     stride: 0,
+    /// };
+    /// let format = HardwareBuffer::negotiate_format(
+    ///     &[
+    ///         HardwareBufferFormat::R10G10B10A2_UNORM,
+    ///         HardwareBufferFormat::R8G8B8A8_UNORM,
+    ///     ],
+    ///     desc,
+    /// );
+    /// ```
+    #[cfg(feature = "api-level-29")]
+    pub fn negotiate_format(
+        candidates: &[HardwareBufferFormat],
+        desc: HardwareBufferDesc,
+    ) -> Option<HardwareBufferFormat> {
+        candidates
+            .iter()
+            .copied()
+            .find(|&format| Self::is_supported(HardwareBufferDesc { format, ..desc }))
+    }
+
     /// Get the system-wide unique id for this [`HardwareBuffer`].
+    ///
+    /// The id identifies the underlying buffer, not this handle: it is identical for every
+    /// [`HardwareBuffer`] and [`HardwareBufferRef`] referring to the same [`ffi::AHardwareBuffer`],
+    /// including those obtained through [`HardwareBuffer::acquire()`], [`Clone`] or a round-trip
+    /// through Java, and it is never reused for a different buffer while the system is running.
+    ///
+    /// This makes it a suitable key for caching per-buffer state that is expensive to recreate,
+    /// such as an imported `EGLImageKHR` or `VkImage`. When cycling a small pool of buffers,
+    /// import each buffer once, store the result keyed by this id, and look it up again whenever
+    /// the buffer is handed back for reuse instead of importing it every frame. Drop the cached
+    /// entry when the last [`HardwareBufferRef`] to the buffer is released.
+    ///
+    /// ```no_run
+    /// # use ndk::hardware_buffer::HardwareBufferRef;
+    /// # fn f(buffer: HardwareBufferRef) -> std::io::Result<()> {
+    /// let clone = buffer.clone();
+    /// assert_eq!(buffer.id()?, clone.id()?);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[cfg(feature = "api-level-31")]
     #[doc(alias = "AHardwareBuffer_getId")]
     pub fn id(&self) -> Result<u64> {
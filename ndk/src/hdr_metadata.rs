@@ -0,0 +1,124 @@
+//! Bindings for the HDR static metadata structures from `<android/hdr_metadata.h>`
+//!
+//! With the `serde` feature enabled, [`ColorXy`], [`HdrMetadataSmpte2086`] and
+//! [`HdrMetadataCta861_3`] are serialized as structs with their field names:
+//!
+//! ```
+//! # #[cfg(feature = "serde")] {
+//! use ndk::hdr_metadata::{ColorXy, HdrMetadataSmpte2086};
+//!
+//! let metadata = HdrMetadataSmpte2086 {
+//!     white_point: ColorXy { x: 0.3127, y: 0.329 },
+//!     max_luminance: 1000.0,
+//!     min_luminance: 0.005,
+//!     ..Default::default()
+//! };
+//! let json = serde_json::to_string(&metadata).unwrap();
+//! assert!(json.contains(r#""white_point":{"x":0.3127,"y":0.329}"#));
+//! assert_eq!(serde_json::from_str::<HdrMetadataSmpte2086>(&json).unwrap(), metadata);
+//! # }
+//! ```
+
+/// A CIE 1931 xy chromaticity coordinate.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[doc(alias = "AColor_xy")]
+pub struct ColorXy {
+    /// Unitless CIE 1931 x coordinate, in `0.0..=1.0`.
+    pub x: f32,
+    /// Unitless CIE 1931 y coordinate, in `0.0..=1.0`.
+    pub y: f32,
+}
+
+impl From<ffi::AColor_xy> for ColorXy {
+    fn from(value: ffi::AColor_xy) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl From<ColorXy> for ffi::AColor_xy {
+    fn from(value: ColorXy) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+/// Mastering display color volume, as defined by SMPTE ST 2086.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[doc(alias = "AHdrMetadata_smpte2086")]
+pub struct HdrMetadataSmpte2086 {
+    /// Chromaticity of the red primary of the mastering display.
+    pub display_primary_red: ColorXy,
+    /// Chromaticity of the green primary of the mastering display.
+    pub display_primary_green: ColorXy,
+    /// Chromaticity of the blue primary of the mastering display.
+    pub display_primary_blue: ColorXy,
+    /// Chromaticity of the white point of the mastering display.
+    pub white_point: ColorXy,
+    /// Maximum luminance of the mastering display, in candelas per square meter (nits).
+    pub max_luminance: f32,
+    /// Minimum luminance of the mastering display, in candelas per square meter (nits).
+    pub min_luminance: f32,
+}
+
+impl From<ffi::AHdrMetadata_smpte2086> for HdrMetadataSmpte2086 {
+    fn from(value: ffi::AHdrMetadata_smpte2086) -> Self {
+        Self {
+            display_primary_red: value.displayPrimaryRed.into(),
+            display_primary_green: value.displayPrimaryGreen.into(),
+            display_primary_blue: value.displayPrimaryBlue.into(),
+            white_point: value.whitePoint.into(),
+            max_luminance: value.maxLuminance,
+            min_luminance: value.minLuminance,
+        }
+    }
+}
+
+impl From<HdrMetadataSmpte2086> for ffi::AHdrMetadata_smpte2086 {
+    fn from(value: HdrMetadataSmpte2086) -> Self {
+        Self {
+            displayPrimaryRed: value.display_primary_red.into(),
+            displayPrimaryGreen: value.display_primary_green.into(),
+            displayPrimaryBlue: value.display_primary_blue.into(),
+            whitePoint: value.white_point.into(),
+            maxLuminance: value.max_luminance,
+            minLuminance: value.min_luminance,
+        }
+    }
+}
+
+/// Content light level metadata, as defined by CTA-861.3.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[doc(alias = "AHdrMetadata_cta861_3")]
+pub struct HdrMetadataCta861_3 {
+    /// Maximum content light level (MaxCLL) of any single pixel, in candelas per square meter
+    /// (nits).
+    pub max_content_light_level: f32,
+    /// Maximum frame-average light level (MaxFALL), in candelas per square meter (nits).
+    pub max_frame_average_light_level: f32,
+}
+
+impl From<ffi::AHdrMetadata_cta861_3> for HdrMetadataCta861_3 {
+    fn from(value: ffi::AHdrMetadata_cta861_3) -> Self {
+        Self {
+            max_content_light_level: value.maxContentLightLevel,
+            max_frame_average_light_level: value.maxFrameAverageLightLevel,
+        }
+    }
+}
+
+impl From<HdrMetadataCta861_3> for ffi::AHdrMetadata_cta861_3 {
+    fn from(value: HdrMetadataCta861_3) -> Self {
+        Self {
+            maxContentLightLevel: value.max_content_light_level,
+            maxFrameAverageLightLevel: value.max_frame_average_light_level,
+        }
+    }
+}
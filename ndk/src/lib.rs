@@ -14,12 +14,14 @@
 pub mod asset;
 pub mod audio;
 pub mod bitmap;
+pub mod choreographer;
 pub mod configuration;
 pub mod data_space;
 pub mod event;
 pub mod font;
 pub mod hardware_buffer;
 pub mod hardware_buffer_format;
+pub mod hdr_metadata;
 pub mod input_queue;
 pub mod looper;
 pub mod media;
@@ -22,7 +22,15 @@ pub type Rect = ffi::ARect;
 /// both ways. Depending on the consumer, images submitted to [`NativeWindow`] can be shown on the
 /// display or sent to other consumers, such as video encoders.
 ///
+/// The NDK does not expose which display a [`NativeWindow`] is presented on. Multi-display apps
+/// have to query the [`android.view.Display`] of the view or activity that owns the window over
+/// JNI, e.g. via [`View.getDisplay()`]. For density decisions alone,
+/// [`Configuration::density()`][crate::configuration::Configuration::density()] of the activity's
+/// configuration reports the dpi of the display it is currently shown on.
+///
 /// [`android.view.Surface`]: https://developer.android.com/reference/android/view/Surface
+/// [`android.view.Display`]: https://developer.android.com/reference/android/view/Display
+/// [`View.getDisplay()`]: https://developer.android.com/reference/android/view/View#getDisplay()
 #[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct NativeWindow {
     ptr: NonNull<ffi::ANativeWindow>,
@@ -407,7 +415,24 @@ bitflags::bitflags! {
     /// Supported transforms are any combination of horizontal mirror, vertical mirror, and
     /// clockwise 90 degree rotation, in that order. Rotations of 180 and 270 degrees are made up
     /// of those basic transforms.
+    ///
+    /// With the `serde` feature enabled, [`NativeWindowTransform`] is serialized as its flag names
+    /// in human-readable formats, and as its bits otherwise:
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde")] {
+    /// use ndk::native_window::NativeWindowTransform;
+    ///
+    /// let transform =
+    ///     NativeWindowTransform::TRANSFORM_MIRROR_HORIZONTAL | NativeWindowTransform::TRANSFORM_ROTATE_90;
+    /// let json = serde_json::to_string(&transform).unwrap();
+    /// assert!(json.contains("TRANSFORM_MIRROR_HORIZONTAL | TRANSFORM_ROTATE_90"));
+    /// assert_eq!(serde_json::from_str::<NativeWindowTransform>(&json).unwrap(), transform);
+    /// # }
+    /// ```
     #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(transparent))]
     #[doc(alias = "ANativeWindowTransform")]
     pub struct NativeWindowTransform : u32 {
         #[doc(alias = "ANATIVEWINDOW_TRANSFORM_IDENTITY")]
@@ -425,6 +450,82 @@ bitflags::bitflags! {
     }
 }
 
+#[cfg(feature = "api-level-26")]
+impl NativeWindowTransform {
+    /// Returns the affine matrix that this transform applies to a buffer of `buffer_size`
+    /// (`width`, `height`) pixels.
+    ///
+    /// The matrix is stored in row-major order and maps column vectors `[x, y, 1]` in buffer
+    /// pixel coordinates, with the origin in the top-left corner of the buffer, to pixel
+    /// coordinates of the transformed output. The output is `buffer_size` large, or
+    /// (`height`, `width`) large if [`Self::TRANSFORM_ROTATE_90`] is set. Coordinates refer to
+    /// pixel edges such that the buffer spans `0..=width` horizontally, hence mirroring maps `x`
+    /// to `width - x`.
+    ///
+    /// As documented on [`NativeWindowTransform`], horizontal mirroring is applied first, then
+    /// vertical mirroring and finally a clockwise rotation by 90 degrees. Bits other than these
+    /// three are ignored.
+    ///
+    /// ```
+    /// # use ndk::native_window::NativeWindowTransform;
+    /// assert_eq!(
+    ///     NativeWindowTransform::TRANSFORM_ROTATE_90.to_matrix3((1920, 1080)),
+    ///     [0.0, -1.0, 1080.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0]
+    /// );
+    /// assert_eq!(
+    ///     NativeWindowTransform::TRANSFORM_ROTATE_180.to_matrix3((1920, 1080)),
+    ///     [-1.0, 0.0, 1920.0, 0.0, -1.0, 1080.0, 0.0, 0.0, 1.0]
+    /// );
+    /// assert_eq!(
+    ///     NativeWindowTransform::TRANSFORM_ROTATE_270.to_matrix3((1920, 1080)),
+    ///     [0.0, 1.0, 0.0, -1.0, 0.0, 1920.0, 0.0, 0.0, 1.0]
+    /// );
+    /// ```
+    pub fn to_matrix3(&self, buffer_size: (i32, i32)) -> [f32; 9] {
+        let (width, height) = (buffer_size.0 as f32, buffer_size.1 as f32);
+        let mut m = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        if self.contains(Self::TRANSFORM_MIRROR_HORIZONTAL) {
+            // x' = width - x
+            m = [-m[0], -m[1], width - m[2], m[3], m[4], m[5], 0.0, 0.0, 1.0];
+        }
+        if self.contains(Self::TRANSFORM_MIRROR_VERTICAL) {
+            // y' = height - y
+            m = [m[0], m[1], m[2], -m[3], -m[4], height - m[5], 0.0, 0.0, 1.0];
+        }
+        if self.contains(Self::TRANSFORM_ROTATE_90) {
+            // x' = height - y, y' = x
+            m = [-m[3], -m[4], height - m[5], m[0], m[1], m[2], 0.0, 0.0, 1.0];
+        }
+        m
+    }
+
+    /// Returns the transform whose [`to_matrix3()`][Self::to_matrix3()] for `buffer_size` is
+    /// approximately equal to `matrix`, or [`None`] if `matrix` is not one of the eight
+    /// combinations of mirroring and 90 degree rotations that a [`NativeWindowTransform`] can
+    /// represent.
+    ///
+    /// ```
+    /// # use ndk::native_window::NativeWindowTransform;
+    /// let matrix = NativeWindowTransform::TRANSFORM_ROTATE_270.to_matrix3((640, 480));
+    /// assert_eq!(
+    ///     NativeWindowTransform::from_matrix3(&matrix, (640, 480)),
+    ///     Some(NativeWindowTransform::TRANSFORM_ROTATE_270)
+    /// );
+    /// let scale = [2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0];
+    /// assert_eq!(NativeWindowTransform::from_matrix3(&scale, (640, 480)), None);
+    /// ```
+    pub fn from_matrix3(matrix: &[f32; 9], buffer_size: (i32, i32)) -> Option<Self> {
+        const EPSILON: f32 = 1e-3;
+        (0..8).map(Self::from_bits_retain).find(|transform| {
+            transform
+                .to_matrix3(buffer_size)
+                .iter()
+                .zip(matrix)
+                .all(|(a, b)| (a - b).abs() <= EPSILON)
+        })
+    }
+}
+
 #[cfg(feature = "api-level-28")]
 #[derive(Debug, Error)]
 pub enum GetDataSpaceError {